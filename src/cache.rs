@@ -0,0 +1,143 @@
+//! An optional on-disk cache for [`search`] results backed by SQLite.
+//!
+//! [`SearchCache`] mirrors the persistent dictionary database used by tools such as `inflectived`:
+//! it stores every parsed [`Search`] keyed by its query string so that repeated lookups — or
+//! lookups made while the network is flaky — can be served locally. Each row carries the time it
+//! was fetched, which [`search_cached`] uses together with a configurable TTL to expire stale
+//! entries.
+//!
+//! This module is only available when the `cache` feature is enabled.
+//!
+//! [`search`]: crate::search
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::errors::{DictionaryError, Result};
+use crate::{search, Search};
+
+/// The default time-to-live applied to cached entries: one week, in seconds.
+pub const DEFAULT_TTL_SECS: u64 = 60 * 60 * 24 * 7;
+
+/// A persistent, SQLite-backed cache of [`Search`] results.
+///
+/// [`Search`]: crate::Search
+pub struct SearchCache {
+    conn: Connection,
+    ttl_secs: u64,
+    offline: bool,
+}
+
+impl SearchCache {
+    /// Opens (creating if necessary) a cache stored at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_connection(Connection::open(path).map_err(wrap)?)
+    }
+
+    /// Opens a cache held entirely in memory, discarded when dropped.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory().map_err(wrap)?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS search_cache (
+                query      TEXT PRIMARY KEY,
+                payload    TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL
+            )",
+            [],
+        )
+        .map_err(wrap)?;
+        Ok(SearchCache {
+            conn,
+            ttl_secs: DEFAULT_TTL_SECS,
+            offline: false,
+        })
+    }
+
+    /// Sets the staleness threshold, in seconds, after which cached entries are ignored.
+    pub fn with_ttl(mut self, ttl_secs: u64) -> Self {
+        self.ttl_secs = ttl_secs;
+        self
+    }
+
+    /// Enables offline mode, in which a cache miss is a hard error rather than a network fetch.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Returns `true` if the cache is in offline mode.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Looks up a fresh (non-stale) cached result for `query`, if one exists.
+    pub fn get(&self, query: &str) -> Result<Option<Search>> {
+        let row: Option<(String, u64)> = self
+            .conn
+            .query_row(
+                "SELECT payload, fetched_at FROM search_cache WHERE query = ?1",
+                params![query],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(wrap)?;
+
+        match row {
+            Some((payload, fetched_at)) if now() <= fetched_at + self.ttl_secs => {
+                let search = serde_json::from_str(&payload).map_err(wrap)?;
+                Ok(Some(search))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Stores `search` under `query`, replacing any previous entry.
+    pub fn put(&self, query: &str, search: &Search) -> Result<()> {
+        let payload = serde_json::to_string(search).map_err(wrap)?;
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO search_cache (query, payload, fetched_at)
+                 VALUES (?1, ?2, ?3)",
+                params![query, payload, now()],
+            )
+            .map_err(wrap)?;
+        Ok(())
+    }
+}
+
+/// Searches for a word, serving the result from `cache` when a fresh entry exists.
+///
+/// On a cache miss the live [`search`] request is issued and its result written back, unless the
+/// cache is in offline mode — in which case [`DictionaryError::Offline`] is returned.
+///
+/// [`search`]: crate::search
+pub async fn search_cached(cache: &SearchCache, word: &str) -> Result<Search> {
+    if let Some(hit) = cache.get(word)? {
+        return Ok(hit);
+    }
+    if cache.offline {
+        return Err(DictionaryError::Offline(word.to_string()).into());
+    }
+    let result = search(word).await?;
+    cache.put(word, &result)?;
+    Ok(result)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn wrap<E>(err: E) -> failure::Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    failure::Error::from_boxed_compat(Box::new(err))
+}
@@ -19,4 +19,18 @@ pub enum DictionaryError {
     /// Error when trying to search for an empty string
     #[fail(display = "empty word was given")]
     EmptyWord,
+    /// Error when a word is not cached and the network is disabled
+    #[fail(display = "`{}` is not cached and offline mode is enabled", _0)]
+    Offline(String),
+    /// Error when the queried word has no matching cards and no suggestions
+    #[fail(display = "cannot find the word `{}`", _0)]
+    WordNotFound(String),
+    /// Error when the queried word has no matching cards but Daum suggested alternatives
+    #[fail(display = "cannot find `{}`; did you mean: {}", query, suggestions.join(", "))]
+    RelativeResultFound {
+        /// The query that produced no direct matches
+        query: String,
+        /// Alternative search terms suggested by the Daum dictionary
+        suggestions: Vec<String>,
+    },
 }
@@ -0,0 +1,225 @@
+//! Approximate IPA-to-Hangul transcription of English pronunciations.
+//!
+//! Daum returns the pronunciation of English head-words as an IPA string (for example
+//! `[rizíst]`). [`ipa_to_hangul`] renders such a string into a rough Hangul spelling, composing
+//! syllables with the Unicode Hangul algorithm, which Korean learners can use as a reading aid.
+//! The mapping is deliberately approximate — IPA and Korean phonology do not line up one to one —
+//! and is in the spirit of the `korean-phonetics-transcriber` project.
+
+const HANGUL_BASE: u32 = 0xAC00;
+
+// Compatibility jamo in Unicode collation order, indexed by their position in the Hangul
+// composition formula.
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+const JUNGSEONG: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+const JONGSEONG: [char; 28] = [
+    '\0', 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ',
+    'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// The filler vowel ㅡ (eu), used to make a bare consonant into a legal syllable.
+const FILLER_JUNG: char = 'ㅡ';
+
+#[derive(Clone, Copy)]
+enum Jamo {
+    Consonant(char),
+    Vowel(char),
+}
+
+/// Converts an IPA pronunciation string into an approximate Hangul spelling.
+///
+/// Surrounding brackets, stress marks and length/diacritic modifiers are stripped, the remaining
+/// IPA is segmented into phonemes, each phoneme is mapped to a Korean jamo, and the jamo are
+/// composed into syllables. An empty or fully unmappable input yields an empty string.
+pub fn ipa_to_hangul(ipa: &str) -> String {
+    let jamo = segment(ipa);
+    compose(&jamo)
+}
+
+/// Strips decoration and segments the IPA string into a flat list of jamo.
+fn segment(ipa: &str) -> Vec<Jamo> {
+    let mut jamo = Vec::new();
+    let mut chars = ipa.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // Brackets, stress marks, length marks and whitespace carry no segmental content.
+            '[' | ']' | '/' | 'ˈ' | 'ˌ' | 'ː' | 'ˑ' | '\'' | '.' | ' ' => continue,
+            // Affricates and digraphs are two IPA symbols but a single Korean onset.
+            't' if matches!(chars.peek(), Some('ʃ')) => {
+                chars.next();
+                jamo.push(Jamo::Consonant('ㅊ'));
+            }
+            'd' if matches!(chars.peek(), Some('ʒ')) => {
+                chars.next();
+                jamo.push(Jamo::Consonant('ㅈ'));
+            }
+            _ => {
+                if let Some(mapped) = map_phoneme(c) {
+                    jamo.push(mapped);
+                }
+            }
+        }
+    }
+    jamo
+}
+
+/// Maps a single IPA symbol to a Korean jamo, if one is defined.
+fn map_phoneme(c: char) -> Option<Jamo> {
+    use Jamo::{Consonant, Vowel};
+    let jamo = match c {
+        // Consonants
+        'p' | 'f' => Consonant('ㅍ'),
+        'b' | 'v' => Consonant('ㅂ'),
+        't' | 'θ' => Consonant('ㅌ'),
+        'd' | 'ð' => Consonant('ㄷ'),
+        'k' => Consonant('ㅋ'),
+        'g' | 'ɡ' => Consonant('ㄱ'),
+        's' | 'ʃ' => Consonant('ㅅ'),
+        'z' | 'ʒ' | 'ʤ' => Consonant('ㅈ'),
+        'ʧ' => Consonant('ㅊ'),
+        'h' => Consonant('ㅎ'),
+        'm' => Consonant('ㅁ'),
+        'n' | 'ŋ' => Consonant('ㄴ'),
+        'l' | 'r' | 'ɹ' => Consonant('ㄹ'),
+        // Glides behave like the onset ㅇ before their following vowel.
+        'j' | 'w' => Consonant('ㅇ'),
+        // Vowels
+        'i' | 'ɪ' | 'í' | 'ì' => Vowel('ㅣ'),
+        'e' | 'ɛ' | 'é' | 'è' => Vowel('ㅔ'),
+        'æ' => Vowel('ㅐ'),
+        'ə' | 'ɜ' | 'ʌ' | 'ɐ' => Vowel('ㅓ'),
+        'a' | 'ɑ' | 'α' | 'ά' | 'á' | 'à' => Vowel('ㅏ'),
+        'ɒ' | 'ɔ' | 'o' | 'ó' | 'ò' => Vowel('ㅗ'),
+        'u' | 'ʊ' | 'ú' | 'ù' => Vowel('ㅜ'),
+        _ => return None,
+    };
+    Some(jamo)
+}
+
+/// A syllable under construction.
+#[derive(Default)]
+struct Builder {
+    choseong: Option<char>,
+    jungseong: Option<char>,
+    jongseong: Option<char>,
+}
+
+impl Builder {
+    fn is_empty(&self) -> bool {
+        self.choseong.is_none() && self.jungseong.is_none() && self.jongseong.is_none()
+    }
+}
+
+/// Assembles jamo into composed Hangul syllables.
+fn compose(jamo: &[Jamo]) -> String {
+    let mut out = String::new();
+    let mut cur = Builder::default();
+
+    for &token in jamo {
+        match token {
+            Jamo::Consonant(c) => {
+                if cur.is_empty() {
+                    cur.choseong = Some(c);
+                } else if cur.jungseong.is_none() {
+                    // An onset cluster: finish the pending consonant with the filler vowel.
+                    cur.jungseong = Some(FILLER_JUNG);
+                    flush(&mut out, &mut cur);
+                    cur.choseong = Some(c);
+                } else if cur.jongseong.is_none() {
+                    // Provisionally a coda; a following vowel will move it to the next onset.
+                    cur.jongseong = Some(c);
+                } else {
+                    flush(&mut out, &mut cur);
+                    cur.choseong = Some(c);
+                }
+            }
+            Jamo::Vowel(v) => {
+                if cur.jungseong.is_none() {
+                    if cur.choseong.is_none() {
+                        cur.choseong = Some('ㅇ');
+                    }
+                    cur.jungseong = Some(v);
+                } else if let Some(coda) = cur.jongseong.take() {
+                    // The provisional coda is really the onset of this new syllable.
+                    flush(&mut out, &mut cur);
+                    cur.choseong = Some(coda);
+                    cur.jungseong = Some(v);
+                } else {
+                    flush(&mut out, &mut cur);
+                    cur.choseong = Some('ㅇ');
+                    cur.jungseong = Some(v);
+                }
+            }
+        }
+    }
+
+    if !cur.is_empty() {
+        if cur.jungseong.is_none() {
+            cur.jungseong = Some(FILLER_JUNG);
+        }
+        flush(&mut out, &mut cur);
+    }
+
+    out
+}
+
+/// Composes and appends the current syllable, then resets the builder.
+fn flush(out: &mut String, cur: &mut Builder) {
+    let choseong = cur.choseong.unwrap_or('ㅇ');
+    let jungseong = match cur.jungseong {
+        Some(j) => j,
+        None => FILLER_JUNG,
+    };
+    if let (Some(cho), Some(jung)) = (index_of(&CHOSEONG, choseong), index_of(&JUNGSEONG, jungseong))
+    {
+        let jong = cur
+            .jongseong
+            .and_then(|c| index_of(&JONGSEONG, c))
+            .unwrap_or(0);
+        let code = HANGUL_BASE + (cho * 21 + jung) * 28 + jong;
+        if let Some(syllable) = char::from_u32(code) {
+            out.push(syllable);
+        }
+    }
+    *cur = Builder::default();
+}
+
+fn index_of(table: &[char], c: char) -> Option<u32> {
+    table.iter().position(|&x| x == c).map(|i| i as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ipa_to_hangul;
+
+    #[test]
+    fn transcribes_english_pronunciations() {
+        // Coda → onset promotion across syllables, trailing filler vowel for the final cluster.
+        assert_eq!(ipa_to_hangul("[rizíst]"), "리짓트");
+        // A vowel-initial syllable takes the ㅇ onset.
+        assert_eq!(ipa_to_hangul("[airάnik]"), "아이라닠");
+    }
+
+    #[test]
+    fn single_syllable_and_coda() {
+        assert_eq!(ipa_to_hangul("[zu]"), "주");
+        assert_eq!(ipa_to_hangul("[ɡʊd]"), "굳");
+    }
+
+    #[test]
+    fn onset_cluster_gets_filler_vowel() {
+        assert_eq!(ipa_to_hangul("[str]"), "스트르");
+    }
+
+    #[test]
+    fn empty_or_unmappable_is_empty() {
+        assert_eq!(ipa_to_hangul(""), "");
+        assert_eq!(ipa_to_hangul("[]"), "");
+    }
+}
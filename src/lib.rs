@@ -8,20 +8,27 @@
 //! let res = &daumdic::search("독수리").await.unwrap().words[0];
 //! assert_eq!(res.word, "독수리");
 //! assert_eq!(res.lang, daumdic::Lang::Korean);
-//! println!("{:?} {}", res.pronounce, res.meaning.join(", "));
+//! println!("{:?} {}", res.pronounce, res.meaning().join(", "));
 //! # });
 //! # std::thread::sleep(std::time::Duration::from_secs(1));
 //! ```
 
 #![deny(missing_docs)]
 
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod errors;
+pub mod hangul;
+pub mod suggest;
+
+pub use crate::hangul::ipa_to_hangul;
 
 use scraper::Selector;
 use std::sync::OnceLock;
 
 /// A type indicating the language of a word.
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
 pub enum Lang {
     /// Korean
     Korean,
@@ -36,16 +43,31 @@ pub enum Lang {
     Other(String),
 }
 
+impl Lang {
+    /// Returns the Daum `dic=` query-parameter value that targets this language's sub-dictionary,
+    /// or [`None`] for languages without a dedicated scope.
+    fn dic_param(&self) -> Option<&'static str> {
+        match self {
+            Lang::Korean => Some("kor"),
+            Lang::English => Some("eng"),
+            Lang::Japanese => Some("jp"),
+            Lang::Hanja => Some("hanja"),
+            Lang::Other(_) => None,
+        }
+    }
+}
+
 /// A type that contains the meaning, pronunciation, and language of each word returned by the
 /// [`search`] function.
 ///
 /// [`search`]: crate::search
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
 pub struct Word {
     /// The word returned as a search result
     pub word: String,
-    /// The meaning of the word, primarily written in Korean
-    pub meaning: Vec<String>,
+    /// The senses of the word, grouped by part of speech and primarily written in Korean
+    pub senses: Vec<Sense>,
     /// The pronunciation of the word, primarily using [IPA]
     ///
     /// [IPA]: https://en.wikipedia.org/wiki/International_Phonetic_Alphabet
@@ -54,6 +76,55 @@ pub struct Word {
     pub lang: Lang,
 }
 
+/// A group of glosses that share a part of speech, as returned for a single [`Word`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sense {
+    /// The part-of-speech tag for this group, if the Daum dictionary provided one
+    pub pos: Option<String>,
+    /// The glosses belonging to this sense
+    pub glosses: Vec<String>,
+}
+
+impl Word {
+    /// Returns one comma-joined gloss string per sense.
+    ///
+    /// This stands in for the previous `meaning` field for backward compatibility — one entry per
+    /// `.txt_search` block — except that the part-of-speech marker is no longer part of the text
+    /// and each gloss is trimmed before joining. Consumers that want to render
+    /// part-of-speech groupings should read [`senses`] directly.
+    ///
+    /// [`senses`]: Word::senses
+    pub fn meaning(&self) -> Vec<String> {
+        self.senses
+            .iter()
+            .map(|sense| sense.glosses.join(", "))
+            .collect()
+    }
+}
+
+impl Word {
+    /// Returns an approximate Hangul transcription of this word's pronunciation.
+    ///
+    /// The transcription is only meaningful for English results, whose [`pronounce`] is an IPA
+    /// string; for other languages (or a missing pronunciation) this returns [`None`] and leaves
+    /// the original pronunciation untouched. See [`ipa_to_hangul`] for the mapping.
+    ///
+    /// [`pronounce`]: Word::pronounce
+    pub fn pronounce_hangul(&self) -> Option<String> {
+        if self.lang != Lang::English {
+            return None;
+        }
+        let pronounce = self.pronounce.as_ref()?;
+        let hangul = ipa_to_hangul(pronounce);
+        if hangul.is_empty() {
+            None
+        } else {
+            Some(hangul)
+        }
+    }
+}
+
 impl std::fmt::Display for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if let Lang::Other(ref d) = self.lang {
@@ -63,7 +134,7 @@ impl std::fmt::Display for Word {
         if let Some(ref pronounce) = self.pronounce {
             write!(f, "{}  ", pronounce)?;
         }
-        write!(f, "{}", self.meaning.join(", "))
+        write!(f, "{}", self.meaning().join(", "))
     }
 }
 
@@ -72,6 +143,7 @@ impl std::fmt::Display for Word {
 ///
 /// [`search`]: crate::search
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "cache", derive(serde::Serialize, serde::Deserialize))]
 pub struct Search {
     /// The words returned as search results
     pub words: Vec<Word>,
@@ -86,6 +158,7 @@ struct SelectorSet {
     lang: Selector,
     pronounce: Selector,
     meaning: Selector,
+    pos: Selector,
     alternatives: Selector,
 }
 
@@ -106,20 +179,83 @@ struct SelectorSet {
 /// # Errors
 ///
 /// This function will return an error under the following conditions:
-/// - If the input search term is an empty string
+/// - If the input search term is an empty string ([`EmptyWord`])
+/// - If no cards match the query and Daum suggests no alternatives ([`WordNotFound`])
+/// - If no cards match the query but Daum suggests alternatives ([`RelativeResultFound`])
 /// - If the HTTP GET request fails due to network or server issues
+///
+/// [`EmptyWord`]: errors::DictionaryError::EmptyWord
+/// [`WordNotFound`]: errors::DictionaryError::WordNotFound
+/// [`RelativeResultFound`]: errors::DictionaryError::RelativeResultFound
 pub async fn search(word: &str) -> errors::Result<Search> {
+    search_in(word, &[]).await
+}
+
+/// Searches the [Daum dictionary], restricting the results to the given sub-dictionaries.
+///
+/// Each [`Lang`] in `langs` is translated into a `dic=` query parameter so that Daum only returns
+/// cards from the corresponding sub-dictionary, and any card whose language is not in `langs` is
+/// discarded. An empty `langs` slice searches every dictionary, which is exactly what [`search`]
+/// does.
+///
+/// The scope is applied in two independent places, and callers should be aware of both:
+///
+/// - [`Lang::Korean`], [`Lang::English`], [`Lang::Japanese`] and [`Lang::Hanja`] map to a `dic=`
+///   value; [`Lang::Other`] has no dedicated sub-dictionary, so it does not narrow the request
+///   URL (those languages are only honoured by the client-side filter below).
+/// - The language filter runs on the parsed cards *before* the empty-result check, so requesting a
+///   scope the word does not appear in yields [`WordNotFound`]/[`RelativeResultFound`] even though
+///   the word exists in another dictionary. This is intentional: the result reflects the requested
+///   scope, not the dictionary as a whole.
+///
+/// [Daum Dictionary]: https://dic.daum.net/
+///
+/// # Errors
+///
+/// Returns the same errors as [`search`], with [`WordNotFound`] and [`RelativeResultFound`]
+/// reported when no card in the requested scope matches.
+///
+/// [`WordNotFound`]: errors::DictionaryError::WordNotFound
+/// [`RelativeResultFound`]: errors::DictionaryError::RelativeResultFound
+pub async fn search_in(word: &str, langs: &[Lang]) -> errors::Result<Search> {
     if word.is_empty() {
         return Err(errors::DictionaryError::EmptyWord.into());
     }
 
     let client = reqwest::Client::new();
-    let url = format!("https://dic.daum.net/search.do?q={}", word);
+    let mut url = format!("https://dic.daum.net/search.do?q={}", word);
+    for dic in langs.iter().filter_map(Lang::dic_param) {
+        url.push_str("&dic=");
+        url.push_str(dic);
+    }
 
     let resp = client.get(&url).send().await?;
     let body = resp.text().await?;
     let document = scraper::Html::parse_document(&body);
 
+    let (words, alternatives) = parse_document(&document, langs);
+
+    if words.is_empty() {
+        if alternatives.is_empty() {
+            return Err(errors::DictionaryError::WordNotFound(word.to_string()).into());
+        }
+        return Err(errors::DictionaryError::RelativeResultFound {
+            query: word.to_string(),
+            suggestions: alternatives,
+        }
+        .into());
+    }
+
+    Ok(Search {
+        words,
+        alternatives,
+    })
+}
+
+/// Parses the cards and speller alternatives out of a Daum search-result document.
+///
+/// Words whose language is not in `langs` are discarded; an empty `langs` keeps all of them.
+fn parse_document(document: &scraper::Html, langs: &[Lang]) -> (Vec<Word>, Vec<String>) {
     static SELECTOR_CACHE: OnceLock<SelectorSet> = OnceLock::new();
     let selector = SELECTOR_CACHE.get_or_init(|| SelectorSet {
         card: Selector::parse(".card_word").unwrap(),
@@ -128,10 +264,11 @@ pub async fn search(word: &str) -> errors::Result<Search> {
         lang: Selector::parse(".tit_word").unwrap(),
         pronounce: Selector::parse(".sub_read,.txt_pronounce").unwrap(),
         meaning: Selector::parse(".txt_search").unwrap(),
+        pos: Selector::parse(".txt_pos").unwrap(),
         alternatives: Selector::parse(".link_speller").unwrap(),
     });
 
-    let words = document
+    let words: Vec<Word> = document
         .select(&selector.card)
         .filter_map(|card| {
             let lang = card
@@ -162,35 +299,95 @@ pub async fn search(word: &str) -> errors::Result<Search> {
                         .select(&selector.pronounce)
                         .map(|element| element.text().collect::<Vec<_>>().join(""))
                         .next();
-                    let meaning = item
+                    let senses = item
                         .select(&selector.meaning)
-                        .map(|element| element.text().collect::<Vec<_>>().join(""))
+                        .map(|element| {
+                            let pos = element
+                                .select(&selector.pos)
+                                .map(|pos| pos.text().collect::<Vec<_>>().join("").trim().to_string())
+                                .find(|pos| !pos.is_empty());
+                            // The POS marker lives inside `.txt_search`, so it is also part of the
+                            // node's text; drop it before splitting so it does not leak into the
+                            // first gloss.
+                            let text = element.text().collect::<Vec<_>>().join("");
+                            let text = text.trim();
+                            let text = match &pos {
+                                Some(pos) => text.strip_prefix(pos.as_str()).unwrap_or(text),
+                                None => text,
+                            };
+                            let glosses = text
+                                .split(',')
+                                .map(|gloss| gloss.trim().to_string())
+                                .filter(|gloss| !gloss.is_empty())
+                                .collect::<Vec<_>>();
+                            Sense { pos, glosses }
+                        })
                         .collect::<Vec<_>>();
 
-                    (word, lang.clone(), pronounce, meaning)
+                    (word, lang.clone(), pronounce, senses)
                 })
                 .filter_map(|t| match t {
-                    (Some(word), Some(lang), pronounce, meaning) => {
-                        Some((word, lang, pronounce, meaning))
+                    (Some(word), Some(lang), pronounce, senses) => {
+                        Some((word, lang, pronounce, senses))
                     }
                     _ => None,
                 })
-                .map(|(word, lang, pronounce, meaning)| Word {
+                .map(|(word, lang, pronounce, senses)| Word {
                     word,
                     lang,
                     pronounce,
-                    meaning,
+                    senses,
                 })
                 .next()
         })
+        .filter(|word| langs.is_empty() || langs.contains(&word.lang))
         .collect();
-    let alternatives = document
+    let alternatives: Vec<String> = document
         .select(&selector.alternatives)
         .map(|element| element.text().collect::<Vec<_>>().join(""))
         .collect();
 
-    Ok(Search {
-        words,
-        alternatives,
-    })
+    (words, alternatives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARD: &str = r#"
+        <div class="card_word">
+            <span class="tit_word">영어사전</span>
+            <div class="search_type">
+                <span class="txt_searchword">resist</span>
+                <span class="txt_pronounce">[rizíst]</span>
+                <span class="txt_search"><span class="txt_pos">동사</span> 저항하다, 반대하다</span>
+            </div>
+        </div>
+    "#;
+
+    #[test]
+    fn parses_senses_with_clean_pos() {
+        let document = scraper::Html::parse_document(CARD);
+        let (words, _) = parse_document(&document, &[]);
+
+        assert_eq!(words.len(), 1);
+        let word = &words[0];
+        assert_eq!(word.word, "resist");
+        assert_eq!(word.lang, Lang::English);
+
+        assert_eq!(word.senses.len(), 1);
+        let sense = &word.senses[0];
+        assert_eq!(sense.pos.as_deref(), Some("동사"));
+        assert_eq!(sense.glosses, ["저항하다", "반대하다"]);
+
+        // The POS marker must not leak into the derived meaning accessor.
+        assert_eq!(word.meaning(), ["저항하다, 반대하다"]);
+    }
+
+    #[test]
+    fn scope_filter_discards_other_languages() {
+        let document = scraper::Html::parse_document(CARD);
+        let (words, _) = parse_document(&document, &[Lang::Korean]);
+        assert!(words.is_empty());
+    }
 }
@@ -0,0 +1,150 @@
+//! Local fuzzy spelling correction for queries that Daum returns no results for.
+//!
+//! When a [`search`] turns up no words, Daum's own server-side speller is the only source of
+//! [`alternatives`]. [`search_with_suggestions`] supplements it with a purely local corrector —
+//! in the spirit of the `didyoumean` CLI — that ranks a caller-supplied word list by
+//! Damerau-Levenshtein edit distance to the query and keeps the closest candidates.
+//!
+//! [`search`]: crate::search
+//! [`alternatives`]: crate::Search::alternatives
+
+use std::collections::BinaryHeap;
+
+use crate::errors::{DictionaryError, Result};
+use crate::{search, Search};
+
+/// The number of suggestions [`search_with_suggestions`] retains by default.
+pub const DEFAULT_SUGGESTION_COUNT: usize = 5;
+
+/// Computes the Damerau-Levenshtein (optimal string alignment) distance between two strings.
+///
+/// The distance is measured over Unicode scalar values rather than bytes, so multibyte text such
+/// as Korean or other CJK scripts is handled one character at a time.
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    if m == 0 {
+        return n;
+    }
+    if n == 0 {
+        return m;
+    }
+
+    let mut d = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=n {
+        d[0][j] = j;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = best;
+        }
+    }
+
+    d[m][n]
+}
+
+/// Searches for a word and, when it is not found, fills in local spelling suggestions.
+///
+/// If the live [`search`] finds the word, the result is passed through unchanged. If it fails
+/// with [`WordNotFound`] or [`RelativeResultFound`], every entry of `wordlist` within
+/// `max_distance` of `word` is ranked by ascending edit distance and the closest
+/// [`DEFAULT_SUGGESTION_COUNT`] are merged into [`Search::alternatives`], ahead of any suggestions
+/// Daum already returned. Any other error is propagated unchanged.
+///
+/// [`search`]: crate::search
+/// [`WordNotFound`]: crate::errors::DictionaryError::WordNotFound
+/// [`RelativeResultFound`]: crate::errors::DictionaryError::RelativeResultFound
+pub async fn search_with_suggestions<S: AsRef<str>>(
+    word: &str,
+    wordlist: &[S],
+    max_distance: usize,
+) -> Result<Search> {
+    let mut result = match search(word).await {
+        Ok(result) => return Ok(result),
+        Err(error) => match error.downcast::<DictionaryError>() {
+            Ok(DictionaryError::WordNotFound(_)) => Search {
+                words: Vec::new(),
+                alternatives: Vec::new(),
+            },
+            Ok(DictionaryError::RelativeResultFound { suggestions, .. }) => Search {
+                words: Vec::new(),
+                alternatives: suggestions,
+            },
+            Ok(other) => return Err(other.into()),
+            Err(error) => return Err(error),
+        },
+    };
+
+    // Bounded max-heap of `(distance, candidate)`; the entry with the largest distance is evicted
+    // once the heap grows past the number of suggestions we intend to keep.
+    let mut heap: BinaryHeap<(usize, String)> = BinaryHeap::new();
+    for candidate in wordlist {
+        let candidate = candidate.as_ref();
+        let distance = damerau_levenshtein(word, candidate);
+        if distance > max_distance {
+            continue;
+        }
+        heap.push((distance, candidate.to_string()));
+        if heap.len() > DEFAULT_SUGGESTION_COUNT {
+            heap.pop();
+        }
+    }
+
+    let mut ranked = heap.into_vec();
+    ranked.sort();
+    let mut suggestions: Vec<String> = ranked.into_iter().map(|(_, candidate)| candidate).collect();
+    suggestions.extend(result.alternatives.drain(..));
+    suggestions.dedup();
+    result.alternatives = suggestions;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::damerau_levenshtein;
+
+    #[test]
+    fn identical_is_zero() {
+        assert_eq!(damerau_levenshtein("resist", "resist"), 0);
+    }
+
+    #[test]
+    fn transposition_counts_as_one() {
+        assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+        assert_eq!(damerau_levenshtein("resist", "rsesit"), 2);
+    }
+
+    #[test]
+    fn substitution_and_indel() {
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+        assert_eq!(damerau_levenshtein("resist", "resit"), 1);
+    }
+
+    #[test]
+    fn counts_cjk_by_character_not_byte() {
+        // Differs by a single Hangul syllable, not by its three UTF-8 bytes.
+        assert_eq!(damerau_levenshtein("강", "간"), 1);
+        assert_eq!(damerau_levenshtein("독수리", "독수리"), 0);
+    }
+
+    #[test]
+    fn empty_arms() {
+        assert_eq!(damerau_levenshtein("", ""), 0);
+        assert_eq!(damerau_levenshtein("", "abc"), 3);
+        assert_eq!(damerau_levenshtein("abc", ""), 3);
+    }
+}
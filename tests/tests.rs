@@ -1,3 +1,4 @@
+use daumdic::errors::DictionaryError;
 use daumdic::{search, Lang};
 use tokio::time::{interval, Duration};
 
@@ -11,20 +12,28 @@ async fn test() {
     interval.tick().await;
 
     // not_found
-    let res = search("asdfaserqfasd").await.unwrap();
-    assert!(res.words.is_empty());
+    let err = search("asdfaserqfasd").await.unwrap_err();
+    match err.downcast::<DictionaryError>() {
+        Ok(DictionaryError::WordNotFound(query)) => assert_eq!(query, "asdfaserqfasd"),
+        other => panic!("expected WordNotFound, got {:?}", other),
+    }
     interval.tick().await;
 
     // alternatives
-    let res = search("resista").await.unwrap();
-    assert!(!res.alternatives.is_empty());
-    assert_eq!(res.alternatives[0], "resist");
+    let err = search("resista").await.unwrap_err();
+    match err.downcast::<DictionaryError>() {
+        Ok(DictionaryError::RelativeResultFound { suggestions, .. }) => {
+            assert!(!suggestions.is_empty());
+            assert_eq!(suggestions[0], "resist");
+        }
+        other => panic!("expected RelativeResultFound, got {:?}", other),
+    }
     interval.tick().await;
 
     // korean
     let res = &search("독수리").await.unwrap().words[0];
     assert_eq!(res.word, "독수리");
-    assert!(!res.meaning.is_empty());
+    assert!(!res.meaning().is_empty());
     assert!(res.pronounce.is_some());
     assert_eq!(res.lang, Lang::Korean);
     interval.tick().await;
@@ -32,7 +41,7 @@ async fn test() {
     // english
     let res = &search("resist").await.unwrap().words[0];
     assert_eq!(res.word, "resist");
-    assert!(!res.meaning.is_empty());
+    assert!(!res.meaning().is_empty());
     assert!(res.pronounce.is_some());
     assert_eq!(res.lang, Lang::English);
     interval.tick().await;
@@ -40,14 +49,14 @@ async fn test() {
     // japanese
     let res = &search("あと").await.unwrap().words[0];
     assert_eq!(res.word, "あと");
-    assert!(!res.meaning.is_empty());
+    assert!(!res.meaning().is_empty());
     assert_eq!(res.lang, Lang::Japanese);
     interval.tick().await;
 
     // hanja
     let res = &search("方").await.unwrap().words[0];
     assert_eq!(res.word, "方");
-    assert!(!res.meaning.is_empty());
+    assert!(!res.meaning().is_empty());
     assert!(res.pronounce.is_some());
     assert_eq!(res.lang, Lang::Hanja);
     interval.tick().await;
@@ -55,7 +64,7 @@ async fn test() {
     // other
     let res = &search("加油站").await.unwrap().words[0];
     assert_eq!(res.word, "加油站");
-    assert!(!res.meaning.is_empty());
+    assert!(!res.meaning().is_empty());
     assert!(res.pronounce.is_some());
     assert_eq!(res.lang, Lang::Other("중국어사전".to_owned()));
 }